@@ -0,0 +1,71 @@
+#![deny(clippy::perf, clippy::correctness, clippy::complexity, clippy::style, missing_debug_implementations)]
+#![warn(clippy::pedantic)]
+
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+fn random_in_unit_disk() -> Vec3 {
+  let point_in_unit_disk;
+  loop {
+    let potential_point_in_unit_disk = 2.0 * Vec3::new(fastrand::f32(), fastrand::f32(), 0.0) - Vec3::new(1.0, 1.0, 0.0);
+    if potential_point_in_unit_disk.squared_length() < 1.0 {
+      point_in_unit_disk = potential_point_in_unit_disk;
+      break;
+    }
+  }
+  point_in_unit_disk
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+pub struct Camera {
+  origin: Vec3,
+  lower_left_corner: Vec3,
+  horizontal: Vec3,
+  vertical: Vec3,
+  u: Vec3,
+  v: Vec3,
+  w: Vec3,
+  lens_radius: f32,
+  time0: f32,
+  time1: f32,
+}
+
+#[allow(dead_code)]
+impl Camera {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    lookfrom: Vec3,
+    lookat: Vec3,
+    vup: Vec3,
+    vfov: f32,
+    aspect: f32,
+    aperture: f32,
+    focus_dist: f32,
+    time0: f32,
+    time1: f32,
+  ) -> Self {
+    let theta = vfov.to_radians();
+    let h = (theta / 2.0).tan();
+    let viewport_height = 2.0 * h;
+    let viewport_width = aspect * viewport_height;
+    let w = (lookfrom - lookat).unit_vector();
+    let u = vup.cross(&w).unit_vector();
+    let v = w.cross(&u);
+    let origin = lookfrom;
+    let horizontal = focus_dist * viewport_width * u;
+    let vertical = focus_dist * viewport_height * v;
+    let lower_left_corner = origin - horizontal / 2.0 - vertical / 2.0 - focus_dist * w;
+    Self { origin, lower_left_corner, horizontal, vertical, u, v, w, lens_radius: aperture / 2.0, time0, time1 }
+  }
+  pub fn get_ray(&self, s: f32, t: f32) -> Ray<'_, '_> {
+    let rd = self.lens_radius * random_in_unit_disk();
+    let offset = self.u * rd.x() + self.v * rd.y();
+    let time = self.time0 + fastrand::f32() * (self.time1 - self.time0);
+    Ray::new(
+      (self.origin + offset).into(),
+      (self.lower_left_corner + s * self.horizontal + t * self.vertical - self.origin - offset).into(),
+      time,
+    )
+  }
+}
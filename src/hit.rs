@@ -0,0 +1,71 @@
+#![deny(clippy::perf, clippy::correctness, clippy::complexity, clippy::style, missing_debug_implementations)]
+#![warn(clippy::pedantic)]
+
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct HitRecord<'a, 'b> {
+  parameter: f32,
+  point: Cow<'a, Vec3>,
+  normal: Cow<'b, Vec3>,
+  material: Box<dyn Material + Send + Sync>,
+}
+
+#[allow(dead_code)]
+impl<'a, 'b> HitRecord<'a, 'b> {
+  pub fn new(parameter: f32, point: Cow<'a, Vec3>, normal: Cow<'b, Vec3>, material: Box<dyn Material + Send + Sync>) -> Self {
+    Self { parameter, point, normal, material }
+  }
+  pub fn parameter(&self) -> f32 {
+    self.parameter
+  }
+  pub fn point(&self) -> &Cow<'a, Vec3> {
+    &self.point
+  }
+  pub fn normal(&self) -> &Cow<'b, Vec3> {
+    &self.normal
+  }
+  pub fn material(&self) -> &dyn Material {
+    self.material.as_ref()
+  }
+}
+
+pub trait Hittable: Debug {
+  fn is_hit(&self, ray: &Ray, parameter_min: f32, parameter_max: f32) -> Option<HitRecord<'_, '_>>;
+}
+
+#[derive(Debug, Default)]
+pub struct HittableList {
+  list: Vec<Box<dyn Hittable + Send + Sync>>,
+}
+
+#[allow(dead_code)]
+impl HittableList {
+  pub fn new() -> Self {
+    Self { list: Vec::new() }
+  }
+  pub fn list(&self) -> &Vec<Box<dyn Hittable + Send + Sync>> {
+    &self.list
+  }
+  pub fn list_mut(&mut self) -> &mut Vec<Box<dyn Hittable + Send + Sync>> {
+    &mut self.list
+  }
+}
+
+impl Hittable for HittableList {
+  fn is_hit(&self, ray: &Ray, parameter_min: f32, parameter_max: f32) -> Option<HitRecord<'_, '_>> {
+    let mut closest_so_far = parameter_max;
+    let mut closest_hit = None;
+    for hittable in &self.list {
+      if let Some(hit_record) = hittable.is_hit(ray, parameter_min, closest_so_far) {
+        closest_so_far = hit_record.parameter();
+        closest_hit = Some(hit_record);
+      }
+    }
+    closest_hit
+  }
+}
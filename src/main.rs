@@ -1,34 +1,94 @@
 #![deny(clippy::perf, clippy::correctness, clippy::complexity, clippy::style, missing_debug_implementations)]
 #![warn(clippy::pedantic)]
 
+mod camera;
 mod hit;
+mod material;
+mod medium;
 mod ray;
 mod sphere;
 mod vec3;
 
+use camera::Camera;
 use hit::{Hittable, HittableList};
+use material::{Dielectric, DiffuseLight, Lambertian, Metal};
+use medium::ConstantMedium;
 use ray::Ray;
-use sphere::Sphere;
-use std::{borrow::Cow, usize};
+use sphere::{MovingSphere, Sphere};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 use vec3::Vec3;
 
-fn color(ray: &Ray, world: &HittableList) -> Vec3 {
-  world.is_hit(ray, 0.0, f32::MAX).map_or_else(
+const MAX_DEPTH: i32 = 50;
+
+fn color(ray: &Ray, world: &HittableList, background: Option<Vec3>, depth: i32) -> Vec3 {
+  if depth <= 0 {
+    return Vec3::new(0.0, 0.0, 0.0);
+  }
+  world.is_hit(ray, 0.001, f32::MAX).map_or_else(
     || {
-      let unit_direction = ray.direction().unit_vector();
-      let lerp_factor = 0.5 * (unit_direction.y() + 1.0);
-      (1.0 - lerp_factor) as f32 * Vec3::new(1.0, 1.0, 1.0) + lerp_factor as f32 * Vec3::new(0.5, 0.7, 1.0)
+      background.unwrap_or_else(|| {
+        let unit_direction = ray.direction().unit_vector();
+        let lerp_factor = 0.5 * (unit_direction.y() + 1.0);
+        (1.0 - lerp_factor) * Vec3::new(1.0, 1.0, 1.0) + lerp_factor * Vec3::new(0.5, 0.7, 1.0)
+      })
     },
     |hit_record| {
-      0.5
-        * match *hit_record.normal() {
-          Cow::Borrowed(normal) => Vec3::new(normal.x() + 1.0, normal.y() + 1.0, normal.z() + 1.0),
-          Cow::Owned(normal) => Vec3::new(normal.x() + 1.0, normal.y() + 1.0, normal.z() + 1.0),
-        }
+      let emitted = hit_record.material().emitted();
+      hit_record.material().scatter(ray, &hit_record).map_or(emitted, |scatter_result| {
+        emitted + **scatter_result.attenuation() * color(scatter_result.scattered(), world, background, depth - 1)
+      })
     },
   )
 }
 
+fn build_scene() -> HittableList {
+  let mut world = HittableList::new();
+  world.list_mut().push(Box::new(Sphere::new(
+    Vec3::new(0.0, 0.0, -1.0).into(),
+    0.5,
+    Box::new(Lambertian::new(Vec3::new(0.8, 0.3, 0.3).into())),
+  )));
+  world.list_mut().push(Box::new(Sphere::new(
+    Vec3::new(0.0, -100.5, -1.0).into(),
+    100.0,
+    Box::new(Lambertian::new(Vec3::new(0.8, 0.8, 0.0).into())),
+  )));
+  world.list_mut().push(Box::new(Sphere::new(
+    Vec3::new(1.0, 0.0, -1.0).into(),
+    0.5,
+    Box::new(Metal::new(Vec3::new(0.8, 0.6, 0.2).into(), 0.3)),
+  )));
+  world.list_mut().push(Box::new(Sphere::new(
+    Vec3::new(-1.0, 0.0, -1.0).into(),
+    0.5,
+    Box::new(Dielectric::new(1.5)),
+  )));
+  world.list_mut().push(Box::new(MovingSphere::new(
+    Vec3::new(-0.4, 0.0, -0.6).into(),
+    Vec3::new(-0.4, 0.3, -0.6).into(),
+    0.0,
+    1.0,
+    0.2,
+    Box::new(Lambertian::new(Vec3::new(0.4, 0.2, 0.1).into())),
+  )));
+  world.list_mut().push(Box::new(Sphere::new(
+    Vec3::new(0.6, 1.0, -0.5).into(),
+    0.3,
+    Box::new(DiffuseLight::new(Vec3::new(4.0, 4.0, 4.0).into())),
+  )));
+  world.list_mut().push(Box::new(ConstantMedium::new(
+    Box::new(Sphere::new(
+      Vec3::new(-0.6, 0.0, -0.3).into(),
+      0.25,
+      Box::new(Lambertian::new(Vec3::new(0.0, 0.0, 0.0).into())),
+    )),
+    4.0,
+    &Vec3::new(0.8, 0.8, 0.8),
+  )));
+  world
+}
+
 #[allow(
   clippy::similar_names,
   clippy::cast_possible_truncation,
@@ -38,24 +98,69 @@ fn color(ray: &Ray, world: &HittableList) -> Vec3 {
 fn main() {
   let number_of_x_pixels = 200;
   let number_of_y_pixels = 100;
+  let samples_per_pixel = 100;
+  let num_threads = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+  let lookfrom = Vec3::new(3.0, 3.0, 2.0);
+  let lookat = Vec3::new(0.0, 0.0, -1.0);
+  let focus_dist = (lookfrom - lookat).length();
+  let aperture = 2.0;
+  let camera = Camera::new(
+    lookfrom,
+    lookat,
+    Vec3::new(0.0, 1.0, 0.0),
+    20.0,
+    number_of_x_pixels as f32 / number_of_y_pixels as f32,
+    aperture,
+    focus_dist,
+    0.0,
+    1.0,
+  );
+  let background = None;
+  let world = build_scene();
+  let mut framebuffer = vec![Vec3::new(0.0, 0.0, 0.0); number_of_x_pixels * number_of_y_pixels];
+  let next_row = AtomicUsize::new(0);
+  let completed_rows = Mutex::new(Vec::<(usize, Vec<Vec3>)>::new());
+  std::thread::scope(|scope| {
+    for thread_index in 0..num_threads {
+      let next_row = &next_row;
+      let completed_rows = &completed_rows;
+      let world = &world;
+      scope.spawn(move || {
+        fastrand::seed(thread_index as u64);
+        loop {
+          let current_y_pixel = next_row.fetch_add(1, Ordering::Relaxed);
+          if current_y_pixel >= number_of_y_pixels {
+            break;
+          }
+          let mut row = Vec::with_capacity(number_of_x_pixels);
+          for current_x_pixel in 0..number_of_x_pixels {
+            let mut pixel_color = Vec3::new(0.0, 0.0, 0.0);
+            for _ in 0..samples_per_pixel {
+              let u = (current_x_pixel as f32 + fastrand::f32()) / number_of_x_pixels as f32;
+              let v = (current_y_pixel as f32 + fastrand::f32()) / number_of_y_pixels as f32;
+              let ray = camera.get_ray(u, v);
+              pixel_color = pixel_color + color(&ray, world, background, MAX_DEPTH);
+            }
+            row.push(pixel_color / samples_per_pixel as f32);
+          }
+          completed_rows.lock().unwrap().push((current_y_pixel, row));
+        }
+      });
+    }
+  });
+  let mut rows = completed_rows.into_inner().unwrap();
+  rows.sort_unstable_by_key(|(current_y_pixel, _)| *current_y_pixel);
+  for (current_y_pixel, row) in rows {
+    framebuffer[current_y_pixel * number_of_x_pixels..(current_y_pixel + 1) * number_of_x_pixels].copy_from_slice(&row);
+  }
   println!("P3\n{} {}\n255", number_of_x_pixels, number_of_y_pixels);
-  let lower_left_corner = Vec3::new(-2.0, -1.0, -1.0);
-  let horizontal = Vec3::new(4.0, 0.0, 0.0);
-  let vertical = Vec3::new(0.0, 2.0, 0.0);
-  let origin = Vec3::new(0.0, 0.0, 0.0);
-  let mut world = HittableList::new();
-  world.list_mut().push(Box::new(Sphere::new(Vec3::new(0.0, 0.0, -1.0).into(), 0.5)));
-  world.list_mut().push(Box::new(Sphere::new(Vec3::new(0.0, -100.5, -1.0).into(), 100.0)));
-  (0..number_of_y_pixels).rev().for_each(|current_y_pixel| {
-    (0..number_of_x_pixels).for_each(|current_x_pixel| {
-      let u = current_x_pixel as f32 / number_of_x_pixels as f32;
-      let v = current_y_pixel as f32 / number_of_y_pixels as f32;
-      let ray = Ray::new((&origin).into(), (lower_left_corner + u * horizontal + v * vertical).into());
-      let pixel_color = color(&ray, &world);
-      let red_value = (255.99 * pixel_color.r()) as usize;
-      let green_value = (255.99 * pixel_color.g()) as usize;
-      let blue_value = (255.99 * pixel_color.b()) as usize;
+  for current_y_pixel in (0..number_of_y_pixels).rev() {
+    for current_x_pixel in 0..number_of_x_pixels {
+      let pixel_color = framebuffer[current_y_pixel * number_of_x_pixels + current_x_pixel];
+      let red_value = (255.99 * pixel_color.r().sqrt()) as usize;
+      let green_value = (255.99 * pixel_color.g().sqrt()) as usize;
+      let blue_value = (255.99 * pixel_color.b().sqrt()) as usize;
       println!("{} {} {}", red_value, green_value, blue_value);
-    })
-  })
+    }
+  }
 }
@@ -42,7 +42,10 @@ impl<'a, 'b, 'c> ScatterResult<'a, 'b, 'c> {
 }
 
 pub trait Material: Debug + DynClone {
-  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult>;
+  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult<'_, '_, '_>>;
+  fn emitted(&self) -> Vec3 {
+    Vec3::new(0.0, 0.0, 0.0)
+  }
 }
 
 clone_trait_object!(Material);
@@ -63,9 +66,55 @@ impl<'a> Lambertian<'a> {
 }
 
 impl<'a> Material for Lambertian<'a> {
-  fn scatter(&self, _: &Ray, hit_record: &HitRecord) -> Option<ScatterResult<'a, 'a, 'a>> {
+  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult<'a, 'a, 'a>> {
     let target = *(*hit_record.point()) + *(*hit_record.normal()) + random_in_unit_sphere();
-    let scattered = Ray::new((*(*hit_record.point())).into(), (target - *(*hit_record.point())).into());
+    let scattered = Ray::new((*(*hit_record.point())).into(), (target - *(*hit_record.point())).into(), ray_in.time());
+    Some(ScatterResult::new((*self.albedo).into(), scattered))
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffuseLight<'a> {
+  emit: Cow<'a, Vec3>,
+}
+
+#[allow(dead_code)]
+impl<'a> DiffuseLight<'a> {
+  pub fn new(emit: Cow<'a, Vec3>) -> Self {
+    Self { emit }
+  }
+  pub fn emit(&self) -> &Cow<'a, Vec3> {
+    &self.emit
+  }
+}
+
+impl Material for DiffuseLight<'_> {
+  fn scatter(&self, _ray_in: &Ray, _hit_record: &HitRecord) -> Option<ScatterResult<'_, '_, '_>> {
+    None
+  }
+  fn emitted(&self) -> Vec3 {
+    *self.emit
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Isotropic<'a> {
+  albedo: Cow<'a, Vec3>,
+}
+
+#[allow(dead_code)]
+impl<'a> Isotropic<'a> {
+  pub fn new(albedo: Cow<'a, Vec3>) -> Self {
+    Self { albedo }
+  }
+  pub fn albedo(&self) -> &Cow<'a, Vec3> {
+    &self.albedo
+  }
+}
+
+impl<'a> Material for Isotropic<'a> {
+  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult<'a, 'a, 'a>> {
+    let scattered = Ray::new((**hit_record.point()).into(), random_in_unit_sphere().into(), ray_in.time());
     Some(ScatterResult::new((*self.albedo).into(), scattered))
   }
 }
@@ -94,10 +143,10 @@ impl<'a> Metal<'a> {
   }
 }
 
-impl<'m> Material for Metal<'m> {
-  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult> {
+impl Material for Metal<'_> {
+  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult<'_, '_, '_>> {
     let reflected = reflect(&ray_in.direction().unit_vector(), hit_record.normal());
-    let scattered = Ray::new((**hit_record.point()).into(), (reflected + self.fuzz() * random_in_unit_sphere()).into());
+    let scattered = Ray::new((**hit_record.point()).into(), (reflected + self.fuzz() * random_in_unit_sphere()).into(), ray_in.time());
     if scattered.direction().dot(hit_record.normal()) > 0.0 {
       Some(ScatterResult::new((*self.albedo).into(), scattered))
     } else {
@@ -135,7 +184,7 @@ impl Dielectric {
 }
 
 impl Material for Dielectric {
-  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult> {
+  fn scatter(&self, ray_in: &Ray, hit_record: &HitRecord) -> Option<ScatterResult<'_, '_, '_>> {
     let reflected = reflect(ray_in.direction(), hit_record.normal());
     let attenuation = Vec3::new(1.0, 1.0, 1.0);
     let (outward_normal, ni_over_nt, cosine) = if ray_in.direction().dot(hit_record.normal()) > 0.0 {
@@ -152,11 +201,11 @@ impl Material for Dielectric {
       1.0
     };
     let scattered = if fastrand::f32() < reflect_probability {
-      Ray::new((**hit_record.point()).into(), reflected.into())
+      Ray::new((**hit_record.point()).into(), reflected.into(), ray_in.time())
     } else {
       refraction_result.map_or_else(
         || unreachable!("Refraction not possible"),
-        |refracted| Ray::new((**hit_record.point()).into(), refracted.into()),
+        |refracted| Ray::new((**hit_record.point()).into(), refracted.into(), ray_in.time()),
       )
     };
     Some(ScatterResult::new(attenuation.into(), scattered))
@@ -0,0 +1,51 @@
+#![deny(clippy::perf, clippy::correctness, clippy::complexity, clippy::style, missing_debug_implementations)]
+#![warn(clippy::pedantic)]
+
+use crate::hit::{HitRecord, Hittable};
+use crate::material::{Isotropic, Material};
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+#[derive(Debug)]
+pub struct ConstantMedium {
+  boundary: Box<dyn Hittable + Send + Sync>,
+  neg_inv_density: f32,
+  phase_function: Box<dyn Material + Send + Sync>,
+}
+
+#[allow(dead_code)]
+impl ConstantMedium {
+  pub fn new(boundary: Box<dyn Hittable + Send + Sync>, density: f32, albedo: &Vec3) -> Self {
+    let phase_function = Box::new(Isotropic::new((*albedo).into()));
+    Self { boundary, neg_inv_density: -1.0 / density, phase_function }
+  }
+  pub fn boundary(&self) -> &dyn Hittable {
+    self.boundary.as_ref()
+  }
+  pub fn phase_function(&self) -> &dyn Material {
+    self.phase_function.as_ref()
+  }
+}
+
+impl Hittable for ConstantMedium {
+  fn is_hit(&self, ray: &Ray, parameter_min: f32, parameter_max: f32) -> Option<HitRecord<'_, '_>> {
+    let entry = self.boundary.is_hit(ray, f32::MIN, f32::MAX)?;
+    let exit = self.boundary.is_hit(ray, entry.parameter() + 0.0001, f32::MAX)?;
+    let mut parameter_enter = entry.parameter().max(parameter_min);
+    let parameter_exit = exit.parameter().min(parameter_max);
+    if parameter_enter >= parameter_exit {
+      return None;
+    }
+    parameter_enter = parameter_enter.max(0.0);
+    let ray_length = ray.direction().length();
+    let distance_inside_boundary = (parameter_exit - parameter_enter) * ray_length;
+    let hit_distance = self.neg_inv_density * fastrand::f32().ln();
+    if hit_distance > distance_inside_boundary {
+      return None;
+    }
+    let parameter = parameter_enter + hit_distance / ray_length;
+    let point = ray.point_at_parameter(parameter);
+    let normal = Vec3::new(1.0, 0.0, 0.0);
+    Some(HitRecord::new(parameter, point.into(), normal.into(), dyn_clone::clone_box(self.phase_function.as_ref())))
+  }
+}
@@ -2,17 +2,19 @@
 #![warn(clippy::pedantic)]
 
 use crate::vec3::Vec3;
+use std::borrow::Cow;
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct Ray {
-  origin: Vec3,
-  direction: Vec3,
+pub struct Ray<'a, 'b> {
+  origin: Cow<'a, Vec3>,
+  direction: Cow<'b, Vec3>,
+  time: f32,
 }
 
 #[allow(dead_code)]
-impl Ray {
-  pub fn new(origin: &Vec3, direction: &Vec3) -> Self {
-    Self { origin: *origin, direction: *direction }
+impl<'a, 'b> Ray<'a, 'b> {
+  pub fn new(origin: Cow<'a, Vec3>, direction: Cow<'b, Vec3>, time: f32) -> Self {
+    Self { origin, direction, time }
   }
   pub fn origin(&self) -> &Vec3 {
     &self.origin
@@ -20,7 +22,10 @@ impl Ray {
   pub fn direction(&self) -> &Vec3 {
     &self.direction
   }
+  pub fn time(&self) -> f32 {
+    self.time
+  }
   pub fn point_at_parameter(&self, scalar_length: f32) -> Vec3 {
-    self.origin + scalar_length * self.direction
+    *self.origin + scalar_length * *self.direction
   }
 }
@@ -0,0 +1,104 @@
+#![deny(clippy::perf, clippy::correctness, clippy::complexity, clippy::style, missing_debug_implementations)]
+#![warn(clippy::pedantic)]
+
+use crate::hit::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+use std::borrow::Cow;
+
+#[derive(Clone, Debug)]
+pub struct Sphere<'a> {
+  center: Cow<'a, Vec3>,
+  radius: f32,
+  material: Box<dyn Material + Send + Sync>,
+}
+
+#[allow(dead_code)]
+impl<'a> Sphere<'a> {
+  pub fn new(center: Cow<'a, Vec3>, radius: f32, material: Box<dyn Material + Send + Sync>) -> Self {
+    Self { center, radius, material }
+  }
+  pub fn center(&self) -> &Cow<'a, Vec3> {
+    &self.center
+  }
+  pub fn radius(&self) -> f32 {
+    self.radius
+  }
+  pub fn material(&self) -> &dyn Material {
+    self.material.as_ref()
+  }
+}
+
+impl Hittable for Sphere<'_> {
+  fn is_hit(&self, ray: &Ray, parameter_min: f32, parameter_max: f32) -> Option<HitRecord<'_, '_>> {
+    hit_sphere(ray, parameter_min, parameter_max, *self.center, self.radius, self.material.as_ref())
+  }
+}
+
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct MovingSphere<'a, 'b> {
+  center0: Cow<'a, Vec3>,
+  center1: Cow<'b, Vec3>,
+  time0: f32,
+  time1: f32,
+  radius: f32,
+  material: Box<dyn Material + Send + Sync>,
+}
+
+#[allow(dead_code)]
+impl<'a, 'b> MovingSphere<'a, 'b> {
+  pub fn new(
+    center0: Cow<'a, Vec3>,
+    center1: Cow<'b, Vec3>,
+    time0: f32,
+    time1: f32,
+    radius: f32,
+    material: Box<dyn Material + Send + Sync>,
+  ) -> Self {
+    Self { center0, center1, time0, time1, radius, material }
+  }
+  pub fn center(&self, time: f32) -> Vec3 {
+    *self.center0 + ((time - self.time0) / (self.time1 - self.time0)) * (*self.center1 - *self.center0)
+  }
+  pub fn radius(&self) -> f32 {
+    self.radius
+  }
+  pub fn material(&self) -> &dyn Material {
+    self.material.as_ref()
+  }
+}
+
+impl Hittable for MovingSphere<'_, '_> {
+  fn is_hit(&self, ray: &Ray, parameter_min: f32, parameter_max: f32) -> Option<HitRecord<'_, '_>> {
+    hit_sphere(ray, parameter_min, parameter_max, self.center(ray.time()), self.radius, self.material.as_ref())
+  }
+}
+
+fn hit_sphere<'h>(
+  ray: &Ray,
+  parameter_min: f32,
+  parameter_max: f32,
+  center: Vec3,
+  radius: f32,
+  material: &(dyn Material + Send + Sync + 'static),
+) -> Option<HitRecord<'h, 'h>> {
+  let oc = *ray.origin() - center;
+  let a = ray.direction().squared_length();
+  let b = oc.dot(ray.direction());
+  let c = oc.squared_length() - radius * radius;
+  let discriminant = b * b - a * c;
+  if discriminant <= 0.0 {
+    return None;
+  }
+  let sqrt_discriminant = discriminant.sqrt();
+  for root in [(-b - sqrt_discriminant) / a, (-b + sqrt_discriminant) / a] {
+    if root < parameter_max && root > parameter_min {
+      let point = ray.point_at_parameter(root);
+      let normal = (point - center) / radius;
+      return Some(HitRecord::new(root, point.into(), normal.into(), dyn_clone::clone_box(material)));
+    }
+  }
+  None
+}
@@ -0,0 +1,136 @@
+#![deny(clippy::perf, clippy::correctness, clippy::complexity, clippy::style, missing_debug_implementations)]
+#![warn(clippy::pedantic)]
+
+use std::borrow::Cow;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3 {
+  e: [f32; 3],
+}
+
+#[allow(dead_code)]
+impl Vec3 {
+  pub fn new(x: f32, y: f32, z: f32) -> Self {
+    Self { e: [x, y, z] }
+  }
+  pub fn x(&self) -> f32 {
+    self.e[0]
+  }
+  pub fn y(&self) -> f32 {
+    self.e[1]
+  }
+  pub fn z(&self) -> f32 {
+    self.e[2]
+  }
+  pub fn r(&self) -> f32 {
+    self.e[0]
+  }
+  pub fn g(&self) -> f32 {
+    self.e[1]
+  }
+  pub fn b(&self) -> f32 {
+    self.e[2]
+  }
+  pub fn squared_length(&self) -> f32 {
+    self.e[0] * self.e[0] + self.e[1] * self.e[1] + self.e[2] * self.e[2]
+  }
+  pub fn length(&self) -> f32 {
+    self.squared_length().sqrt()
+  }
+  pub fn dot(&self, other: &Vec3) -> f32 {
+    self.e[0] * other.e[0] + self.e[1] * other.e[1] + self.e[2] * other.e[2]
+  }
+  pub fn cross(&self, other: &Vec3) -> Vec3 {
+    Vec3::new(
+      self.e[1] * other.e[2] - self.e[2] * other.e[1],
+      self.e[2] * other.e[0] - self.e[0] * other.e[2],
+      self.e[0] * other.e[1] - self.e[1] * other.e[0],
+    )
+  }
+  pub fn unit_vector(&self) -> Vec3 {
+    *self / self.length()
+  }
+}
+
+impl From<Vec3> for Cow<'_, Vec3> {
+  fn from(vector: Vec3) -> Self {
+    Cow::Owned(vector)
+  }
+}
+
+impl<'a> From<&'a Vec3> for Cow<'a, Vec3> {
+  fn from(vector: &'a Vec3) -> Self {
+    Cow::Borrowed(vector)
+  }
+}
+
+impl Add for Vec3 {
+  type Output = Vec3;
+  fn add(self, other: Vec3) -> Vec3 {
+    Vec3::new(self.e[0] + other.e[0], self.e[1] + other.e[1], self.e[2] + other.e[2])
+  }
+}
+
+impl Sub for Vec3 {
+  type Output = Vec3;
+  fn sub(self, other: Vec3) -> Vec3 {
+    Vec3::new(self.e[0] - other.e[0], self.e[1] - other.e[1], self.e[2] - other.e[2])
+  }
+}
+
+impl Sub<Vec3> for &Vec3 {
+  type Output = Vec3;
+  fn sub(self, other: Vec3) -> Vec3 {
+    Vec3::new(self.e[0] - other.e[0], self.e[1] - other.e[1], self.e[2] - other.e[2])
+  }
+}
+
+impl Neg for Vec3 {
+  type Output = Vec3;
+  fn neg(self) -> Vec3 {
+    Vec3::new(-self.e[0], -self.e[1], -self.e[2])
+  }
+}
+
+impl Mul<Vec3> for Vec3 {
+  type Output = Vec3;
+  fn mul(self, other: Vec3) -> Vec3 {
+    Vec3::new(self.e[0] * other.e[0], self.e[1] * other.e[1], self.e[2] * other.e[2])
+  }
+}
+
+impl Mul<Vec3> for f32 {
+  type Output = Vec3;
+  fn mul(self, vector: Vec3) -> Vec3 {
+    Vec3::new(self * vector.e[0], self * vector.e[1], self * vector.e[2])
+  }
+}
+
+impl Mul<&Vec3> for f32 {
+  type Output = Vec3;
+  fn mul(self, vector: &Vec3) -> Vec3 {
+    Vec3::new(self * vector.e[0], self * vector.e[1], self * vector.e[2])
+  }
+}
+
+impl Mul<f32> for Vec3 {
+  type Output = Vec3;
+  fn mul(self, scalar: f32) -> Vec3 {
+    Vec3::new(self.e[0] * scalar, self.e[1] * scalar, self.e[2] * scalar)
+  }
+}
+
+impl Mul<f32> for &Vec3 {
+  type Output = Vec3;
+  fn mul(self, scalar: f32) -> Vec3 {
+    Vec3::new(self.e[0] * scalar, self.e[1] * scalar, self.e[2] * scalar)
+  }
+}
+
+impl Div<f32> for Vec3 {
+  type Output = Vec3;
+  fn div(self, scalar: f32) -> Vec3 {
+    Vec3::new(self.e[0] / scalar, self.e[1] / scalar, self.e[2] / scalar)
+  }
+}